@@ -123,12 +123,22 @@ impl<'a> ListObjectVersions<'a> {
     }
 
     /// Specify the key to start with when listing.
+    ///
+    /// Replaces any `key-marker` set by a previous call, rather than adding
+    /// a second one, so this stays safe to call repeatedly to page through
+    /// a listing with the same action instance.
     pub fn with_key_marker(&mut self, key: impl Into<Cow<'a, str>>) {
+        self.query_mut().remove("key-marker");
         self.query_mut().insert("key-marker", key);
     }
 
     /// Specify the object version you want to start listing from.
+    ///
+    /// Replaces any `version-id-marker` set by a previous call, rather than
+    /// adding a second one, so this stays safe to call repeatedly to page
+    /// through a listing with the same action instance.
     pub fn with_version_id_marker(&mut self, version: impl Into<Cow<'a, str>>) {
+        self.query_mut().remove("version-id-marker");
         self.query_mut().insert("version-id-marker", version);
     }
 
@@ -178,6 +188,25 @@ impl<'a> ListObjectVersions<'a> {
     }
 }
 
+impl<'a> crate::paginate::Paginate<'a> for ListObjectVersions<'a> {
+    type Response = ListObjectVersionsResponse;
+
+    fn parse_response(body: &str) -> Result<Self::Response, quick_xml::DeError> {
+        Self::parse_response(body)
+    }
+
+    fn advance(&mut self, response: &Self::Response) -> bool {
+        match (&response.next_key_marker, &response.next_version_id_marker) {
+            (Some(key_marker), Some(version_id_marker)) => {
+                self.with_key_marker(key_marker.clone());
+                self.with_version_id_marker(version_id_marker.clone());
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
 impl<'a> S3Action<'a> for ListObjectVersions<'a> {
     const METHOD: Method = Method::Get;
 
@@ -284,4 +313,90 @@ mod tests {
         assert_eq!(v.version_id, "3/L4kqtJl40Nr8X8gdRQBpUMLUo");
         assert!(v.owner.is_none());
     }
+
+    #[test]
+    fn advance_carries_both_markers_together() {
+        use crate::paginate::Paginate;
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut action = ListObjectVersions::new(&bucket, None);
+
+        let truncated = ListObjectVersionsResponse {
+            versions: Vec::new(),
+            delete_markers: Vec::new(),
+            common_prefixes: Vec::new(),
+            max_keys: None,
+            next_key_marker: Some("my-image.jpg".to_owned()),
+            next_version_id_marker: Some("3/L4kqtJl40Nr8X8gdRQBpUMLUo".to_owned()),
+        };
+        assert!(action.advance(&truncated));
+        let url = action.sign(Duration::from_secs(86400));
+        assert!(url.as_str().contains("key-marker=my-image.jpg"));
+        assert!(url
+            .as_str()
+            .contains("version-id-marker=3%2FL4kqtJl40Nr8X8gdRQBpUMLUo"));
+
+        let complete = ListObjectVersionsResponse {
+            versions: Vec::new(),
+            delete_markers: Vec::new(),
+            common_prefixes: Vec::new(),
+            max_keys: None,
+            next_key_marker: None,
+            next_version_id_marker: None,
+        };
+        assert!(!action.advance(&complete));
+    }
+
+    #[test]
+    fn advance_replaces_the_previous_markers_instead_of_duplicating_them() {
+        use crate::paginate::Paginate;
+
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+
+        let mut action = ListObjectVersions::new(&bucket, None);
+
+        let page_one = ListObjectVersionsResponse {
+            versions: Vec::new(),
+            delete_markers: Vec::new(),
+            common_prefixes: Vec::new(),
+            max_keys: None,
+            next_key_marker: Some("my-image.jpg".to_owned()),
+            next_version_id_marker: Some("3/L4kqtJl40Nr8X8gdRQBpUMLUo".to_owned()),
+        };
+        assert!(action.advance(&page_one));
+
+        let page_two = ListObjectVersionsResponse {
+            versions: Vec::new(),
+            delete_markers: Vec::new(),
+            common_prefixes: Vec::new(),
+            max_keys: None,
+            next_key_marker: Some("other-image.jpg".to_owned()),
+            next_version_id_marker: Some("QUpfdndnIGByIxe7rC0vXnbeqKLrRLIT".to_owned()),
+        };
+        assert!(action.advance(&page_two));
+
+        let url = action.sign(Duration::from_secs(86400));
+        let query = url.query().unwrap();
+        assert_eq!(query.matches("key-marker=").count(), 1);
+        assert_eq!(query.matches("version-id-marker=").count(), 1);
+        assert!(query.contains("key-marker=other-image.jpg"));
+        assert!(query.contains("version-id-marker=QUpfdndnIGByIxe7rC0vXnbeqKLrRLIT"));
+        assert!(!query.contains("my-image.jpg"));
+        assert!(!query.contains("3%2FL4kqtJl40Nr8X8gdRQBpUMLUo"));
+    }
 }
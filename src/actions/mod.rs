@@ -0,0 +1,87 @@
+//! S3 actions: requests that can be signed and sent to an S3-compatible
+//! endpoint.
+
+mod copy_object;
+mod delete_object;
+mod delete_objects;
+mod get_object;
+mod head_object;
+mod list_object_versions;
+mod put_bucket_versioning;
+mod put_object;
+pub(crate) mod xml_ser;
+
+pub use copy_object::CopyObject;
+pub use delete_object::DeleteObject;
+pub use delete_objects::{DeleteObjects, ObjectIdentifier};
+pub use get_object::GetObject;
+pub use head_object::HeadObject;
+pub use list_object_versions::{
+    CommonPrefixes, DeleteMarker, ListObjectVersions, ListObjectVersionsResponse,
+    ListObjectsOwner, ObjectVersion,
+};
+pub use put_bucket_versioning::{PutBucketVersioning, VersioningStatus};
+pub use put_object::PutObject;
+
+use std::time::Duration;
+
+use jiff::Timestamp;
+use url::Url;
+
+use crate::{Bucket, Map};
+
+/// The signed URL for `key` within `bucket`: `bucket.base_url()` with `key`
+/// appended as path segments.
+pub(crate) fn object_url(bucket: &Bucket, key: &str) -> Url {
+    let mut url = bucket.base_url().clone();
+    url.path_segments_mut()
+        .expect("bucket base URLs can always be a base")
+        .extend(key.split('/'));
+    url
+}
+
+/// The HTTP method an [`S3Action`] is sent with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Put,
+    Post,
+    Delete,
+    Head,
+}
+
+impl Method {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Put => "PUT",
+            Self::Post => "POST",
+            Self::Delete => "DELETE",
+            Self::Head => "HEAD",
+        }
+    }
+}
+
+/// A request that can be signed, producing a URL that can be sent to an
+/// S3-compatible endpoint with any HTTP client.
+pub trait S3Action<'a> {
+    /// The HTTP method this action must be sent with.
+    const METHOD: Method;
+
+    /// The action's query parameters, in addition to any it sets up itself.
+    fn query_mut(&mut self) -> &mut Map<'a>;
+
+    /// The action's headers, in addition to any it sets up itself.
+    fn headers_mut(&mut self) -> &mut Map<'a>;
+
+    /// Sign this action as of `time`, producing a URL that is valid for
+    /// `expires_in`.
+    fn sign_with_time(&self, expires_in: Duration, time: &Timestamp) -> Url;
+
+    /// Sign this action, producing a URL that is valid for `expires_in`
+    /// starting now.
+    fn sign(&self, expires_in: Duration) -> Url {
+        self.sign_with_time(expires_in, &Timestamp::now())
+    }
+}
@@ -0,0 +1,46 @@
+//! Shared helpers for serializing request bodies to XML.
+//!
+//! Every body-bearing action should build a small `#[derive(Serialize)]`
+//! struct mirroring the shape of the XML it needs to send, and hand it to
+//! [`to_body`] rather than assembling the string by hand. This guarantees
+//! that any user-controlled string (object keys, prefixes, ...) is escaped
+//! by the same `quick_xml` that already unescapes values when *parsing*
+//! responses such as [`ListObjectVersionsResponse`](crate::actions::ListObjectVersionsResponse).
+
+use serde::Serialize;
+
+/// Serialize a request body to XML.
+///
+/// # Panics
+///
+/// Panics if `value` cannot be represented as XML. This should never happen
+/// for the simple, hand-written request structs this crate serializes.
+pub(crate) fn to_body<T: Serialize>(value: &T) -> String {
+    quick_xml::se::to_string(value).expect("request struct must always serialize to XML")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde::Serialize;
+
+    use super::to_body;
+
+    #[test]
+    fn escapes_special_characters() {
+        #[derive(Serialize)]
+        #[serde(rename = "Key")]
+        struct Key<'a> {
+            #[serde(rename = "$text")]
+            value: &'a str,
+        }
+
+        let key = Key {
+            value: "a&b<c>\"d'",
+        };
+        assert_eq!(
+            to_body(&key),
+            "<Key>a&amp;b&lt;c&gt;&quot;d&apos;</Key>"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use url::Url;
+
+/// How a [`Bucket`]'s name is addressed in its base URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlStyle {
+    /// `https://<endpoint>/<bucket>`
+    Path,
+    /// `https://<bucket>.<endpoint>`
+    VirtualHost,
+}
+
+/// A bucket on an S3-compatible endpoint.
+#[derive(Debug, Clone)]
+pub struct Bucket {
+    base_url: Url,
+    name: String,
+    region: String,
+}
+
+impl Bucket {
+    /// # Errors
+    ///
+    /// Returns an error if `endpoint` has no host, or cannot be a base URL
+    /// (e.g. `data:` URLs).
+    pub fn new(
+        endpoint: Url,
+        style: UrlStyle,
+        name: impl Into<String>,
+        region: impl Into<String>,
+    ) -> Result<Self, BucketError> {
+        let name = name.into();
+        let region = region.into();
+
+        let mut base_url = endpoint;
+        match style {
+            UrlStyle::VirtualHost => {
+                let host = base_url.host_str().ok_or(BucketError::MissingHost)?;
+                let virtual_host = format!("{name}.{host}");
+                base_url
+                    .set_host(Some(&virtual_host))
+                    .map_err(|_| BucketError::InvalidBucketName)?;
+            }
+            UrlStyle::Path => {
+                base_url.host_str().ok_or(BucketError::MissingHost)?;
+                base_url
+                    .path_segments_mut()
+                    .map_err(|()| BucketError::CannotBeABase)?
+                    .push(&name);
+            }
+        }
+
+        Ok(Self {
+            base_url,
+            name,
+            region,
+        })
+    }
+
+    #[must_use]
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+}
+
+/// An error produced while building a [`Bucket`].
+#[derive(Debug)]
+pub enum BucketError {
+    /// The endpoint URL has no host.
+    MissingHost,
+    /// The bucket name could not be used as part of the host.
+    InvalidBucketName,
+    /// The endpoint URL cannot be a base (e.g. `data:` URLs).
+    CannotBeABase,
+}
+
+impl std::fmt::Display for BucketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingHost => write!(f, "endpoint URL has no host"),
+            Self::InvalidBucketName => write!(f, "bucket name is not a valid host label"),
+            Self::CannotBeABase => write!(f, "endpoint URL cannot be a base"),
+        }
+    }
+}
+
+impl std::error::Error for BucketError {}
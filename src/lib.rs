@@ -0,0 +1,17 @@
+//! A minimal, dependency-light S3 client: build a signed [`Url`](url::Url)
+//! (or headers) for an S3 action with any HTTP client you like.
+
+pub mod actions;
+mod bucket;
+mod credentials;
+mod map;
+pub mod paginate;
+pub mod signing;
+mod sorting_iter;
+pub mod sse_c;
+pub mod version_id;
+
+pub use bucket::{Bucket, BucketError, UrlStyle};
+pub use credentials::Credentials;
+pub use map::Map;
+pub use sorting_iter::SortingIterator;
@@ -0,0 +1,57 @@
+use std::borrow::Cow;
+
+/// An ordered multimap of string key/value pairs, used for the query
+/// parameters and headers an [`S3Action`](crate::actions::S3Action) carries
+/// in addition to the ones each action sets up by default.
+#[derive(Debug, Clone, Default)]
+pub struct Map<'a>(Vec<(Cow<'a, str>, Cow<'a, str>)>);
+
+impl<'a> Map<'a> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn insert(&mut self, key: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) {
+        self.0.push((key.into(), value.into()));
+    }
+
+    /// Remove every entry for `key`, e.g. before re-inserting it so a
+    /// setter called twice replaces rather than duplicates its entry.
+    pub fn remove(&mut self, key: &str) {
+        self.0.retain(|(k, _)| k.as_ref() != key);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_ref(), v.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn insert_allows_duplicate_keys() {
+        let mut map = Map::new();
+        map.insert("key-marker", "a");
+        map.insert("key-marker", "b");
+
+        let values: Vec<_> = map.iter().map(|(_, v)| v).collect();
+        assert_eq!(values, ["a", "b"]);
+    }
+
+    #[test]
+    fn remove_drops_every_entry_for_the_key() {
+        let mut map = Map::new();
+        map.insert("key-marker", "a");
+        map.insert("other", "c");
+        map.insert("key-marker", "b");
+        map.remove("key-marker");
+
+        let entries: Vec<_> = map.iter().collect();
+        assert_eq!(entries, [("other", "c")]);
+    }
+}
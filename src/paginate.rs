@@ -0,0 +1,168 @@
+//! Auto-pagination for list actions.
+//!
+//! [`ListObjectVersions`](crate::actions::ListObjectVersions) and other list
+//! actions report a truncated response via marker fields
+//! (`next_key_marker`/`next_version_id_marker`, `next_continuation_token`,
+//! ...) that callers are expected to feed back into a freshly-built action
+//! to get the next page. [`Paginated`] drives that loop for any action that
+//! implements [`Paginate`], so callers don't have to re-implement it.
+//!
+//! This crate is HTTP-client-agnostic, so [`Paginated`] does not perform any
+//! HTTP requests itself: it is handed a `fetch` closure that turns a signed
+//! [`Url`] into a response body, and drives signing, fetching, parsing and
+//! marker advancement around it.
+
+use std::future::Future;
+use std::time::Duration;
+
+use url::Url;
+
+use crate::actions::S3Action;
+
+/// A list action that can be resumed from a truncated response.
+///
+/// Implementors must carry forward *every* marker the response exposes, not
+/// just one of them: for [`ListObjectVersions`](crate::actions::ListObjectVersions)
+/// both `key-marker` and `version-id-marker` must move together, since
+/// advancing only one skips or duplicates versions.
+pub trait Paginate<'a>: S3Action<'a> + Sized {
+    /// The parsed response type for this action.
+    type Response;
+
+    /// Parse the raw XML response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the XML response could not be parsed.
+    fn parse_response(body: &str) -> Result<Self::Response, quick_xml::DeError>;
+
+    /// Advance `self` in place to request the next page.
+    ///
+    /// Returns `true` if `response` indicated there is a next page (and
+    /// `self` was advanced to fetch it), or `false` if the listing is
+    /// complete.
+    fn advance(&mut self, response: &Self::Response) -> bool;
+}
+
+/// Drives a [`Paginate`] action across all of its pages.
+///
+/// Each call to [`next_page`](Self::next_page) signs the current action,
+/// hands the signed [`Url`] to the `fetch` closure, parses the result, and —
+/// if the response was truncated — advances the action so the following
+/// call fetches the next page.
+pub struct Paginated<'a, A, F> {
+    action: Option<A>,
+    expires_in: Duration,
+    fetch: F,
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a, A, F, Fut, E> Paginated<'a, A, F>
+where
+    A: Paginate<'a>,
+    F: FnMut(Url) -> Fut,
+    Fut: Future<Output = Result<String, E>>,
+{
+    /// Create a new paginator, starting from `action`.
+    #[must_use]
+    pub fn new(action: A, expires_in: Duration, fetch: F) -> Self {
+        Self {
+            action: Some(action),
+            expires_in,
+            fetch,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Fetch and parse the next page, advancing internal state for the page
+    /// after that. Returns `None` once the listing is exhausted.
+    pub async fn next_page(&mut self) -> Option<Result<A::Response, PaginateError<E>>> {
+        let mut action = self.action.take()?;
+
+        let url = action.sign(self.expires_in);
+        let body = match (self.fetch)(url).await {
+            Ok(body) => body,
+            Err(err) => {
+                self.action = Some(action);
+                return Some(Err(PaginateError::Fetch(err)));
+            }
+        };
+
+        let response = match A::parse_response(&body) {
+            Ok(response) => response,
+            Err(err) => {
+                self.action = Some(action);
+                return Some(Err(PaginateError::Parse(err)));
+            }
+        };
+
+        if action.advance(&response) {
+            self.action = Some(action);
+        }
+
+        Some(Ok(response))
+    }
+}
+
+/// An error produced while driving a [`Paginated`] listing.
+#[derive(Debug)]
+pub enum PaginateError<E> {
+    /// The `fetch` closure returned an error.
+    Fetch(E),
+    /// The response body could not be parsed as XML.
+    Parse(quick_xml::DeError),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for PaginateError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fetch(err) => write!(f, "failed to fetch page: {err}"),
+            Self::Parse(err) => write!(f, "failed to parse page: {err}"),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + std::fmt::Display> std::error::Error for PaginateError<E> {}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::actions::ListObjectVersions;
+    use crate::{Bucket, UrlStyle};
+
+    #[tokio::test]
+    async fn a_fetch_error_does_not_consume_the_page_so_it_can_be_retried() {
+        let endpoint = "https://s3.amazonaws.com".parse().unwrap();
+        let bucket = Bucket::new(
+            endpoint,
+            UrlStyle::VirtualHost,
+            "examplebucket",
+            "us-east-1",
+        )
+        .unwrap();
+        let action = ListObjectVersions::new(&bucket, None);
+
+        let attempts = Cell::new(0);
+        let mut paginated = Paginated::new(action, Duration::from_secs(60), |_url| {
+            attempts.set(attempts.get() + 1);
+            let attempt = attempts.get();
+            async move {
+                if attempt == 1 {
+                    Err("transient network error")
+                } else {
+                    Ok("<ListVersionsResult></ListVersionsResult>".to_owned())
+                }
+            }
+        });
+
+        let first = paginated.next_page().await;
+        assert!(matches!(first, Some(Err(PaginateError::Fetch(_)))));
+
+        // The failed page's action was not dropped: retrying succeeds.
+        let second = paginated.next_page().await;
+        assert!(matches!(second, Some(Ok(_))));
+        assert_eq!(attempts.get(), 2);
+    }
+}
@@ -0,0 +1,62 @@
+//! Canonical `host` header derivation for signing.
+//!
+//! [`sign`](super::sign) needs a `host` value for both the canonical
+//! request and `SignedHeaders`. For a custom endpoint with an explicit,
+//! non-default port — e.g. a self-hosted MinIO/Garage instance on `:9000` —
+//! that port must be part of the canonical host, or strict servers reject
+//! the signature.
+//!
+//! [`url::Url::port`] already returns `None` for a scheme's default port
+//! (`80` for `http`, `443` for `https`), so [`canonical_host`] only needs to
+//! append whatever port is left.
+
+use url::Url;
+
+/// The canonical `host` value for `url`: `hostname:port` for any non-default
+/// port, or bare `hostname` otherwise.
+#[must_use]
+pub(crate) fn canonical_host(url: &Url) -> String {
+    let host = url.host_str().expect("bucket URLs always have a host");
+    match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{Bucket, UrlStyle};
+
+    #[test]
+    fn default_https_port_is_omitted() {
+        let endpoint: Url = "https://s3.amazonaws.com".parse().unwrap();
+        assert_eq!(canonical_host(&endpoint), "s3.amazonaws.com");
+    }
+
+    #[test]
+    fn explicit_default_port_is_still_omitted() {
+        let endpoint: Url = "https://s3.amazonaws.com:443".parse().unwrap();
+        assert_eq!(canonical_host(&endpoint), "s3.amazonaws.com");
+    }
+
+    #[test]
+    fn non_default_port_is_included_for_virtual_host_style() {
+        let endpoint = "http://localhost:9000".parse().unwrap();
+        let bucket = Bucket::new(endpoint, UrlStyle::VirtualHost, "examplebucket", "garage")
+            .unwrap();
+        assert_eq!(
+            canonical_host(bucket.base_url()),
+            "examplebucket.localhost:9000"
+        );
+    }
+
+    #[test]
+    fn non_default_port_is_included_for_path_style() {
+        let endpoint = "http://localhost:9000".parse().unwrap();
+        let bucket = Bucket::new(endpoint, UrlStyle::Path, "examplebucket", "garage").unwrap();
+        assert_eq!(canonical_host(bucket.base_url()), "localhost:9000");
+    }
+}
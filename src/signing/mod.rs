@@ -0,0 +1,189 @@
+//! AWS4-HMAC-SHA256 request signing.
+//!
+//! [`sign`] builds a presigned URL for a single request: it authenticates
+//! with `UNSIGNED-PAYLOAD` and `host` as the only always-signed header, and
+//! [`S3Action`](crate::actions::S3Action) implementations pass in whatever
+//! extra query parameters and headers they need signed alongside it.
+
+pub(crate) mod host;
+pub mod streaming;
+pub mod util;
+
+use hmac::{Hmac, Mac};
+use jiff::Timestamp;
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use crate::actions::Method;
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts a key of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+/// Derives `kSigning`, the AWS4 signing key used both for a request's own
+/// signature and for each chunk signature in [`streaming`].
+pub(crate) fn derive_signing_key(
+    secret_key: &str,
+    short_date: &str,
+    region: &str,
+    service: &str,
+) -> [u8; 32] {
+    let k_date = hmac_sha256(format!("AWS4{secret_key}").as_bytes(), short_date.as_bytes());
+    let k_region = hmac_sha256(&k_date, region.as_bytes());
+    let k_service = hmac_sha256(&k_region, service.as_bytes());
+    hmac_sha256(&k_service, b"aws4_request")
+}
+
+/// Percent-encode `s` per AWS4's `UriEncode`: everything except the
+/// unreserved characters (`A-Z a-z 0-9 - _ . ~`) is escaped as uppercase
+/// `%XX`.
+fn uri_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char);
+            }
+            _ => out.push_str(&format!("%{b:02X}")),
+        }
+    }
+    out
+}
+
+/// `YYYYMMDDTHHMMSSZ` for `time`, the timestamp format AWS4 signing uses.
+fn format_amz_date(time: &Timestamp) -> String {
+    let secs = time.as_second();
+    let days = secs.div_euclid(86400);
+    let time_of_day = secs.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+    let hour = time_of_day / 3600;
+    let minute = (time_of_day % 3600) / 60;
+    let second = time_of_day % 60;
+    format!("{year:04}{month:02}{day:02}T{hour:02}{minute:02}{second:02}Z")
+}
+
+/// Howard Hinnant's `civil_from_days`: days since the Unix epoch to a
+/// proleptic Gregorian `(year, month, day)`.
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = if m <= 2 { y + 1 } else { y };
+    (y, m as u32, d as u32)
+}
+
+/// Sign a single request, producing a presigned URL valid for
+/// `expires_in_secs` starting at `time`.
+#[allow(clippy::too_many_arguments)]
+pub fn sign<'a>(
+    time: &Timestamp,
+    method: Method,
+    url: Url,
+    access_key: &str,
+    secret_key: &str,
+    session_token: Option<&str>,
+    region: &str,
+    expires_in_secs: u64,
+    query: impl Iterator<Item = (&'a str, &'a str)>,
+    headers: impl Iterator<Item = (&'a str, &'a str)>,
+) -> Url {
+    let amz_date = format_amz_date(time);
+    let short_date = &amz_date[..8];
+    let credential_scope = format!("{short_date}/{region}/s3/aws4_request");
+    let credential = format!("{access_key}/{credential_scope}");
+
+    // `host` always participates in SignedHeaders, alongside whatever other
+    // headers the action asked to have signed (e.g. the SSE-C trio).
+    let host = host::canonical_host(&url);
+    let mut header_pairs: Vec<(String, String)> = headers
+        .map(|(k, v)| (k.to_ascii_lowercase(), v.trim().to_owned()))
+        .collect();
+    header_pairs.push(("host".to_owned(), host));
+    header_pairs.sort();
+    header_pairs.dedup_by(|a, b| a.0 == b.0);
+
+    let signed_headers = header_pairs
+        .iter()
+        .map(|(k, _)| k.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+    let canonical_headers: String = header_pairs
+        .iter()
+        .map(|(k, v)| format!("{k}:{v}\n"))
+        .collect();
+
+    let mut amz_params = vec![
+        ("X-Amz-Algorithm".to_owned(), "AWS4-HMAC-SHA256".to_owned()),
+        ("X-Amz-Credential".to_owned(), credential),
+        ("X-Amz-Date".to_owned(), amz_date.clone()),
+        ("X-Amz-Expires".to_owned(), expires_in_secs.to_string()),
+        ("X-Amz-SignedHeaders".to_owned(), signed_headers.clone()),
+    ];
+    if let Some(token) = session_token {
+        amz_params.push(("X-Amz-Security-Token".to_owned(), token.to_owned()));
+    }
+
+    let mut all_params = amz_params;
+    all_params.extend(query.map(|(k, v)| (k.to_owned(), v.to_owned())));
+    all_params.sort();
+
+    let canonical_query = all_params
+        .iter()
+        .map(|(k, v)| format!("{}={}", uri_encode(k), uri_encode(v)))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let canonical_request = [
+        method.as_str(),
+        url.path(),
+        &canonical_query,
+        &canonical_headers,
+        &signed_headers,
+        "UNSIGNED-PAYLOAD",
+    ]
+    .join("\n");
+
+    let hashed_canonical_request = hex::encode(Sha256::digest(canonical_request.as_bytes()));
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{amz_date}\n{credential_scope}\n{hashed_canonical_request}"
+    );
+
+    let signing_key = derive_signing_key(secret_key, short_date, region, "s3");
+    let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+    let mut url = url;
+    let final_query = format!(
+        "{canonical_query}&X-Amz-Signature={}",
+        uri_encode(&signature)
+    );
+    url.set_query(Some(&final_query));
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn amz_date_formatting() {
+        // Fri, 24 May 2013 00:00:00 GMT
+        let time = Timestamp::from_second(1_369_353_600).unwrap();
+        assert_eq!(format_amz_date(&time), "20130524T000000Z");
+    }
+
+    #[test]
+    fn encodes_reserved_characters() {
+        assert_eq!(uri_encode("a b/c"), "a%20b%2Fc");
+        assert_eq!(uri_encode("abc-._~"), "abc-._~");
+    }
+}
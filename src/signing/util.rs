@@ -0,0 +1,15 @@
+//! Small helpers shared by actions that can be used without credentials.
+
+use url::Url;
+
+/// Append `query` to `url`, for an anonymous (unsigned) request.
+#[must_use]
+pub fn add_query_params<'a>(mut url: Url, query: impl Iterator<Item = (&'a str, &'a str)>) -> Url {
+    {
+        let mut pairs = url.query_pairs_mut();
+        for (key, value) in query {
+            pairs.append_pair(key, value);
+        }
+    }
+    url
+}
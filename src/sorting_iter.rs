@@ -0,0 +1,26 @@
+/// Merges two iterators of key/value pairs into a single iterator sorted by
+/// key, the order AWS4 canonical requests require for query parameters.
+pub struct SortingIterator<'a> {
+    pairs: std::vec::IntoIter<(&'a str, &'a str)>,
+}
+
+impl<'a> SortingIterator<'a> {
+    pub fn new(
+        a: impl Iterator<Item = (&'a str, &'a str)>,
+        b: impl Iterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let mut pairs: Vec<_> = a.chain(b).collect();
+        pairs.sort_by_key(|(key, _)| *key);
+        Self {
+            pairs: pairs.into_iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for SortingIterator<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pairs.next()
+    }
+}